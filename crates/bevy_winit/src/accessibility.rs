@@ -1,31 +1,44 @@
 //! Helpers for mapping window entities to accessibility types
 
+// Forwarding raw keyboard input to the platform assistive technology (for
+// key-echo or AT shortcut interception) is intentionally not implemented: the
+// pinned `accesskit_winit::Adapter` exposes no entry point for relaying
+// device-level keyboard events to the AT, so there is no supported way to drive
+// it from winit. The accessibility surface we can feed is the tree/action path
+// wired up through `AccessKitPlugin` below.
+
 use std::{
     collections::VecDeque,
     sync::{Arc, Mutex},
 };
 
+use accesskit_winit::Adapter;
 use bevy_a11y::{
-    accesskit::ActionRequest, AccessibilityNode, AccessibilityRequested, AccessibilitySystem, Focus,
+    accesskit::{
+        Action, ActionHandler, ActionRequest, NodeBuilder, NodeClassSet, NodeId, Role, Tree,
+        TreeUpdate,
+    },
+    AccessibilityNode, AccessibilityRequested, AccessibilitySystem, Focus,
 };
 use bevy_a11y::{ActionRequest as ActionRequestWrapper, ManageAccessibilityUpdates};
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
-    prelude::{Entity, EventReader, EventWriter},
+    prelude::{Entity, Event, EventReader, EventWriter},
     query::With,
     schedule::IntoSystemConfigs,
     system::{NonSendMut, Query, Res, ResMut, Resource},
 };
 use bevy_hierarchy::{Children, Parent};
+use bevy_utils::HashMap;
 use bevy_window::{PrimaryWindow, Window, WindowClosed};
 
 /// Maps window entities to their `AccessKit` [`Adapter`]s.
 #[derive(Default, Deref, DerefMut)]
-pub struct AccessKitAdapters(pub EntityHashMap<()>);
+pub struct AccessKitAdapters(pub EntityHashMap<Adapter>);
 
-/// Maps window entities to their respective [`WinitActionRequests`]s.
+/// Maps window entities to their respective [`WinitActionRequestHandler`]s.
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct WinitActionRequestHandlers(pub EntityHashMap<Arc<Mutex<WinitActionRequestHandler>>>);
 
@@ -39,18 +52,74 @@ impl WinitActionRequestHandler {
     }
 }
 
+/// Hands `AccessKit` [`ActionRequest`]s coming from winit to the matching
+/// [`WinitActionRequestHandler`] so they can be drained by [`poll_receivers`].
+struct AccessKitActionHandler {
+    requests: Arc<Mutex<WinitActionRequestHandler>>,
+}
+
+impl ActionHandler for AccessKitActionHandler {
+    fn do_action(&mut self, request: ActionRequest) {
+        let mut requests = self.requests.lock().unwrap();
+        requests.push_back(request);
+    }
+}
+
+/// A user-supplied handler for an `AccessKit` [`Action`], invoked by
+/// [`poll_receivers`] in place of the built-in effect for that action.
+pub type AccessibilityActionHandler = Box<dyn Fn(&ActionRequest) + Send + Sync + 'static>;
+
+/// Maps `AccessKit` [`Action`]s to optional handlers that override or extend
+/// the built-in translation performed by [`poll_receivers`].
+///
+/// When a handler is present for an incoming [`ActionRequest`]'s action it is
+/// called and the built-in effect is skipped; actions without a handler use
+/// their default effect, falling back to an [`ActionRequestWrapper`] event for
+/// variants the default layer does not recognize.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct AccessibilityActionHandlers(pub HashMap<Action, AccessibilityActionHandler>);
+
+/// Emitted when an assistive technology requests that an entity be scrolled
+/// into view through [`Action::ScrollIntoView`].
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AccessibilityScrollRequest(pub Entity);
+
+/// Emitted for a generic activation of an entity, triggered by
+/// [`Action::Default`].
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AccessibilityInteraction(pub Entity);
+
 /// Prepares accessibility for a winit window.
 pub(crate) fn prepare_accessibility_for_window(
-    _winit_window: &winit::window::Window,
+    winit_window: &winit::window::Window,
     entity: Entity,
-    _name: String,
-    _accessibility_requested: AccessibilityRequested,
+    name: String,
+    accessibility_requested: AccessibilityRequested,
     adapters: &mut AccessKitAdapters,
     handlers: &mut WinitActionRequestHandlers,
 ) {
+    let mut root_builder = NodeBuilder::new(Role::Window);
+    root_builder.set_name(name.into_boxed_str());
+    let root = root_builder.build(&mut NodeClassSet::lock_global());
+
+    let accesskit_window_id = NodeId(entity.to_bits());
     let action_request_handler = WinitActionRequestHandler::new();
+    let adapter = Adapter::with_action_handler(
+        winit_window,
+        move || {
+            accessibility_requested.set(true);
+            TreeUpdate {
+                nodes: vec![(accesskit_window_id, root)],
+                tree: Some(Tree::new(accesskit_window_id)),
+                focus: accesskit_window_id,
+            }
+        },
+        Box::new(AccessKitActionHandler {
+            requests: action_request_handler.clone(),
+        }),
+    );
 
-    adapters.insert(entity, ());
+    adapters.insert(entity, adapter);
     handlers.insert(entity, action_request_handler);
 }
 
@@ -67,12 +136,35 @@ fn window_closed(
 
 fn poll_receivers(
     handlers: Res<WinitActionRequestHandlers>,
+    action_handlers: Res<AccessibilityActionHandlers>,
+    mut focus: ResMut<Focus>,
+    mut scroll_requests: EventWriter<AccessibilityScrollRequest>,
+    mut interactions: EventWriter<AccessibilityInteraction>,
     mut actions: EventWriter<ActionRequestWrapper>,
 ) {
     for (_id, handler) in handlers.iter() {
         let mut handler = handler.lock().unwrap();
-        while let Some(event) = handler.pop_front() {
-            actions.send(ActionRequestWrapper(event));
+        while let Some(request) = handler.pop_front() {
+            if let Some(action_handler) = action_handlers.get(&request.action) {
+                action_handler(&request);
+                continue;
+            }
+            let Ok(target) = Entity::try_from_bits(request.target.0) else {
+                actions.send(ActionRequestWrapper(request));
+                continue;
+            };
+            match request.action {
+                Action::Focus => **focus = Some(target),
+                Action::ScrollIntoView => {
+                    scroll_requests.send(AccessibilityScrollRequest(target));
+                }
+                Action::Default => {
+                    interactions.send(AccessibilityInteraction(target));
+                }
+                _ => {
+                    actions.send(ActionRequestWrapper(request));
+                }
+            }
         }
     }
 }
@@ -85,17 +177,61 @@ fn should_update_accessibility_nodes(
 }
 
 fn update_accessibility_nodes(
-    mut _adapters: NonSendMut<AccessKitAdapters>,
-    _focus: Res<Focus>,
-    _primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
-    _nodes: Query<(
+    mut adapters: NonSendMut<AccessKitAdapters>,
+    focus: Res<Focus>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    nodes: Query<(
         Entity,
         &AccessibilityNode,
         Option<&Children>,
         Option<&Parent>,
     )>,
-    _node_entities: Query<Entity, With<AccessibilityNode>>,
+    node_entities: Query<Entity, With<AccessibilityNode>>,
 ) {
+    let Ok((primary_window_id, primary_window)) = primary_window.get_single() else {
+        return;
+    };
+    let Some(adapter) = adapters.get_mut(&primary_window_id) else {
+        return;
+    };
+    if nodes.is_empty() {
+        return;
+    }
+    let mut to_update = vec![];
+    let mut window_children = vec![];
+    for (entity, node, children, parent) in &nodes {
+        let mut node = (**node).clone();
+        if parent
+            .map(|parent| !node_entities.contains(**parent))
+            .unwrap_or(true)
+        {
+            window_children.push(NodeId(entity.to_bits()));
+        }
+        if let Some(children) = children {
+            for child in children {
+                if node_entities.contains(*child) {
+                    node.push_child(NodeId(child.to_bits()));
+                }
+            }
+        }
+        to_update.push((
+            NodeId(entity.to_bits()),
+            node.build(&mut NodeClassSet::lock_global()),
+        ));
+    }
+    let mut root_node = NodeBuilder::new(Role::Window);
+    root_node.set_name(primary_window.title.clone().into_boxed_str());
+    root_node.set_children(window_children);
+    let root_node = root_node.build(&mut NodeClassSet::lock_global());
+    to_update.insert(0, (NodeId(primary_window_id.to_bits()), root_node));
+    let focus_id = (*focus)
+        .map(|v| NodeId(v.to_bits()))
+        .unwrap_or_else(|| NodeId(primary_window_id.to_bits()));
+    adapter.update_if_active(|| TreeUpdate {
+        nodes: to_update,
+        tree: None,
+        focus: focus_id,
+    });
 }
 
 /// Implements winit-specific `AccessKit` functionality.
@@ -105,7 +241,10 @@ impl Plugin for AccessKitPlugin {
     fn build(&self, app: &mut App) {
         app.init_non_send_resource::<AccessKitAdapters>()
             .init_resource::<WinitActionRequestHandlers>()
+            .init_resource::<AccessibilityActionHandlers>()
             .add_event::<ActionRequestWrapper>()
+            .add_event::<AccessibilityScrollRequest>()
+            .add_event::<AccessibilityInteraction>()
             .add_systems(
                 PostUpdate,
                 (